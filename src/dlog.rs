@@ -1,11 +1,21 @@
 use k256::{
-    elliptic_curve::{sec1::ToEncodedPoint, Field, PrimeField},
-    ProjectivePoint, Scalar,
+    elliptic_curve::{
+        sec1::{FromEncodedPoint, ToEncodedPoint},
+        Field, PrimeField,
+    },
+    AffinePoint, EncodedPoint, FieldBytes, ProjectivePoint, Scalar,
 };
 use rand_core::OsRng;
 use sha2::{Digest, Sha256};
 use std::io::Error;
 
+use crate::transcript::Transcript;
+
+/// Domain-separation label for the transcript-based DLOG proof below. Bumping this label
+/// (rather than changing the hashing in place) is how this crate versions its Fiat-Shamir
+/// derivation without silently breaking proofs generated against an older label.
+const DLOG_TRANSCRIPT_LABEL: &str = "dlogzkp/dlog/v1";
+
 /// Trait that defines a Discrete Logarithm (DLOG) proof using the Schnorr protocol.
 pub trait DiscreteLogProof {
     /// Hashes a list of points along with a session ID and participant ID.
@@ -169,4 +179,466 @@ impl DLogProof {
     pub fn new(t: ProjectivePoint, s: Scalar) -> Self {
         DLogProof { t, s }
     }
+
+    /// Verifies a batch of DLOG proofs with a single random-linear-combination check,
+    /// evaluated as one multiscalar multiplication instead of per-proof scalar mults.
+    ///
+    /// Instead of checking `base_i * s_i == t_i + y_i * c_i` independently for every proof,
+    /// the equations are combined under fresh random weights `rho_i` (with `rho_0` fixed to
+    /// one to save a sample) into the single aggregate
+    /// `sum(rho_i * base_i * s_i) - sum(rho_i * t_i) - sum((rho_i * c_i) * y_i) == identity`.
+    /// A forged proof can only survive this aggregate check with negligible probability,
+    /// since doing so would require predicting the random weights in advance.
+    ///
+    /// All `(scalar, point)` terms of the aggregate are evaluated together in one
+    /// multiscalar multiplication (`multiscalar_mul`), which shares the point-doublings
+    /// across every term instead of repeating a full scalar multiplication per proof.
+    /// Proofs sharing the same `base_point` (the common case — a server usually verifies
+    /// many proofs against the same generator) additionally fold their `rho_i * s_i`
+    /// coefficients into one combined term per distinct base, so a batch of `n` proofs
+    /// against a single base costs one multiscalar multiplication over roughly `2n + 1`
+    /// terms rather than `2n` separate scalar multiplications.
+    ///
+    /// `verify_batch` only reports whether the whole batch is valid; when it returns
+    /// `Ok(false)`, call [`DLogProof::first_invalid_index`] with the same `items` to find
+    /// which proof was the culprit.
+    /// # Arguments
+    /// * `items` - A slice of `(sid, pid, y, base_point, proof)` tuples to verify together
+    /// # Returns
+    /// `Ok(true)` iff the combined point is the identity, i.e. every proof is valid
+    /// # Example
+    /// ```rust
+    /// use k256::{ProjectivePoint, Scalar};
+    /// use k256::elliptic_curve::Field;
+    /// use rand_core::OsRng;
+    /// use schnorr_zk_dlog::dlog::{DiscreteLogProof, DLogProof};
+    /// let base_point = ProjectivePoint::GENERATOR;
+    /// let x1 = Scalar::random(&mut OsRng);
+    /// let y1 = base_point * x1;
+    /// let proof1 = DLogProof::prove("sid", 1, &x1, &y1, &base_point).unwrap();
+    /// let x2 = Scalar::random(&mut OsRng);
+    /// let y2 = base_point * x2;
+    /// let proof2 = DLogProof::prove("sid", 2, &x2, &y2, &base_point).unwrap();
+    /// let items = vec![
+    ///     ("sid", 1, y1, base_point, &proof1),
+    ///     ("sid", 2, y2, base_point, &proof2),
+    /// ];
+    /// let result = DLogProof::verify_batch(&items).expect("Batch verification failed");
+    /// assert!(result, "Batch of valid proofs should verify");
+    /// ```
+    pub fn verify_batch(
+        items: &[(&str, u32, ProjectivePoint, ProjectivePoint, &DLogProof)],
+    ) -> Result<bool, Error> {
+        if items.is_empty() {
+            return Ok(true);
+        }
+
+        let challenges = Self::batch_challenges(items)?;
+
+        let mut weights = Vec::with_capacity(items.len());
+        weights.push(Scalar::ONE);
+        for _ in 1..items.len() {
+            weights.push(Scalar::random(&mut OsRng));
+        }
+
+        // Group the `rho_i * s_i * base_i` terms by base_point, so proofs sharing a base
+        // (e.g. all verified against the generator) fold into a single term instead of one
+        // per proof.
+        let mut base_terms: Vec<(ProjectivePoint, Scalar)> = Vec::new();
+        for (i, (_, _, _, base_point, proof)) in items.iter().enumerate() {
+            let weighted_s = weights[i] * proof.s;
+            match base_terms.iter_mut().find(|(base, _)| base == base_point) {
+                Some((_, acc)) => *acc += weighted_s,
+                None => base_terms.push((*base_point, weighted_s)),
+            }
+        }
+
+        let mut terms = base_terms;
+        for (i, (_, _, y, _, proof)) in items.iter().enumerate() {
+            let rho = weights[i];
+            let c = challenges[i];
+            terms.push((proof.t, -rho));
+            terms.push((*y, -(rho * c)));
+        }
+
+        let acc = Self::multiscalar_mul(&terms);
+        Ok(acc == ProjectivePoint::IDENTITY)
+    }
+
+    /// Evaluates `sum(scalar_i * point_i)` as a single multiscalar multiplication (Straus'
+    /// method): every term's scalar is walked bit by bit in lockstep, so the whole batch
+    /// shares one chain of doublings instead of each term repeating its own.
+    fn multiscalar_mul(terms: &[(ProjectivePoint, Scalar)]) -> ProjectivePoint {
+        let bits: Vec<FieldBytes> = terms.iter().map(|(_, scalar)| scalar.to_bytes()).collect();
+
+        let mut acc = ProjectivePoint::IDENTITY;
+        for bit_index in 0..256usize {
+            acc += acc;
+
+            let byte_index = bit_index / 8;
+            let bit_in_byte = 7 - (bit_index % 8);
+            for (term_index, (point, _)) in terms.iter().enumerate() {
+                let bit = (bits[term_index][byte_index] >> bit_in_byte) & 1;
+                if bit == 1 {
+                    acc += *point;
+                }
+            }
+        }
+        acc
+    }
+
+    /// Finds the index of the first proof that fails individual verification.
+    ///
+    /// `verify_batch` only tells you whether the whole batch is valid; when it returns
+    /// `Ok(false)` a server usually still wants to know *which* proof was bad so it can
+    /// reject the right request. This falls back to checking proofs one at a time.
+    /// # Arguments
+    /// * `items` - The same `(sid, pid, y, base_point, proof)` tuples passed to `verify_batch`
+    /// # Returns
+    /// `Ok(None)` if every proof verifies individually, otherwise `Ok(Some(index))`
+    pub fn first_invalid_index(
+        items: &[(&str, u32, ProjectivePoint, ProjectivePoint, &DLogProof)],
+    ) -> Result<Option<usize>, Error> {
+        for (i, (sid, pid, y, base_point, proof)) in items.iter().enumerate() {
+            if !proof.verify(sid, *pid, y, base_point)? {
+                return Ok(Some(i));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Generates a DLOG proof using the labeled `Transcript` abstraction instead of the raw
+    /// `sid || pid || points` hashing in `hash_points`.
+    ///
+    /// This is a backward-compatible addition: proofs produced by `prove`/`verify` keep
+    /// working unchanged, and a proof from one derivation will simply fail to verify under
+    /// the other, since `DLOG_TRANSCRIPT_LABEL` domain-separates it from every other use of
+    /// `Transcript` in this crate. Prefer this path for new protocols; it absorbs `sid`,
+    /// `pid`, `base_point`, `y`, and `t` each under their own label, so the same point
+    /// reused in two different roles can never collide in the hash input.
+    /// # Example
+    /// ```rust
+    /// use k256::{ProjectivePoint, Scalar};
+    /// use k256::elliptic_curve::Field;
+    /// use rand_core::OsRng;
+    /// use schnorr_zk_dlog::dlog::DLogProof;
+    /// let x = Scalar::random(&mut OsRng);
+    /// let base_point = ProjectivePoint::GENERATOR;
+    /// let y = base_point * x;
+    /// let proof = DLogProof::prove_with_transcript("sid", 1, &x, &y, &base_point)
+    ///     .expect("Proof generation failed");
+    /// let result = proof
+    ///     .verify_with_transcript("sid", 1, &y, &base_point)
+    ///     .expect("Verification failed");
+    /// assert!(result, "Proof should verify");
+    /// ```
+    pub fn prove_with_transcript(
+        sid: &str,
+        pid: u32,
+        x: &Scalar,
+        y: &ProjectivePoint,
+        base_point: &ProjectivePoint,
+    ) -> Result<Self, Error> {
+        let r = Scalar::random(&mut OsRng);
+        let t = base_point * &r;
+
+        let c = Self::transcript_challenge(sid, pid, base_point, y, &t)?;
+        let s = r + (c * x);
+
+        Ok(DLogProof { t, s })
+    }
+
+    /// Verifies a DLOG proof produced by `prove_with_transcript`.
+    pub fn verify_with_transcript(
+        &self,
+        sid: &str,
+        pid: u32,
+        y: &ProjectivePoint,
+        base_point: &ProjectivePoint,
+    ) -> Result<bool, Error> {
+        let c = Self::transcript_challenge(sid, pid, base_point, y, &self.t)?;
+        let lhs = base_point * &self.s;
+        let rhs = self.t + y * &c;
+        Ok(lhs == rhs)
+    }
+
+    /// Builds the labeled transcript shared by `prove_with_transcript`/`verify_with_transcript`
+    /// and squeezes the challenge scalar out of it.
+    fn transcript_challenge(
+        sid: &str,
+        pid: u32,
+        base_point: &ProjectivePoint,
+        y: &ProjectivePoint,
+        t: &ProjectivePoint,
+    ) -> Result<Scalar, Error> {
+        let mut transcript = Transcript::new(DLOG_TRANSCRIPT_LABEL);
+        transcript.append_message("sid", sid.as_bytes());
+        transcript.append_message("pid", &pid.to_be_bytes());
+        transcript.append_point("base_point", base_point);
+        transcript.append_point("y", y);
+        transcript.append_point("t", t);
+        transcript.challenge_scalar("c")
+    }
+
+    /// Total length in bytes of the canonical `DLogProof` encoding: a 33-byte SEC1-compressed
+    /// `t` followed by a 32-byte big-endian `s`.
+    pub const BYTES_LEN: usize = 65;
+
+    /// Encodes this proof into its canonical fixed-length `(t, s)` byte representation, for
+    /// sending a proof over the wire or storing it.
+    /// # Example
+    /// ```rust
+    /// use k256::{ProjectivePoint, Scalar};
+    /// use k256::elliptic_curve::Field;
+    /// use rand_core::OsRng;
+    /// use schnorr_zk_dlog::dlog::{DiscreteLogProof, DLogProof};
+    /// let x = Scalar::random(&mut OsRng);
+    /// let base_point = ProjectivePoint::GENERATOR;
+    /// let y = base_point * x;
+    /// let proof = DLogProof::prove("sid", 1, &x, &y, &base_point).unwrap();
+    /// let bytes = proof.to_bytes();
+    /// assert_eq!(bytes.len(), DLogProof::BYTES_LEN);
+    /// ```
+    pub fn to_bytes(&self) -> [u8; Self::BYTES_LEN] {
+        let mut bytes = [0u8; Self::BYTES_LEN];
+        let encoded_t = self.t.to_affine().to_encoded_point(true);
+        bytes[..33].copy_from_slice(encoded_t.as_bytes());
+        bytes[33..].copy_from_slice(&self.s.to_bytes());
+        bytes
+    }
+
+    /// Decodes a proof from its canonical `to_bytes` representation.
+    ///
+    /// Rejects non-canonical encodings rather than panicking: the wrong length, a `t` that
+    /// is not on the curve, a `t` that is the identity point, or an `s` that is not strictly
+    /// less than the group order.
+    /// # Example
+    /// ```rust
+    /// use k256::{ProjectivePoint, Scalar};
+    /// use k256::elliptic_curve::Field;
+    /// use rand_core::OsRng;
+    /// use schnorr_zk_dlog::dlog::{DiscreteLogProof, DLogProof};
+    /// let x = Scalar::random(&mut OsRng);
+    /// let base_point = ProjectivePoint::GENERATOR;
+    /// let y = base_point * x;
+    /// let proof = DLogProof::prove("sid", 1, &x, &y, &base_point).unwrap();
+    /// let decoded = DLogProof::from_bytes(&proof.to_bytes()).expect("Decoding failed");
+    /// assert_eq!(proof, decoded);
+    /// assert!(decoded.verify("sid", 1, &y, &base_point).unwrap());
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != Self::BYTES_LEN {
+            return Err(Error::new(
+                std::io::ErrorKind::InvalidData,
+                "DLogProof encoding must be exactly BYTES_LEN bytes",
+            ));
+        }
+
+        let encoded_t = EncodedPoint::from_bytes(&bytes[..33]).map_err(|_| {
+            Error::new(std::io::ErrorKind::InvalidData, "Invalid commitment point encoding")
+        })?;
+        let affine_t: AffinePoint = Option::from(AffinePoint::from_encoded_point(&encoded_t))
+            .ok_or_else(|| {
+                Error::new(std::io::ErrorKind::InvalidData, "Commitment point is not on the curve")
+            })?;
+        let t = ProjectivePoint::from(affine_t);
+        if t == ProjectivePoint::IDENTITY {
+            return Err(Error::new(
+                std::io::ErrorKind::InvalidData,
+                "Commitment point must not be the identity",
+            ));
+        }
+
+        let mut s_repr = FieldBytes::default();
+        s_repr.copy_from_slice(&bytes[33..]);
+        let s: Scalar = Option::from(Scalar::from_repr(s_repr)).ok_or_else(|| {
+            Error::new(std::io::ErrorKind::InvalidData, "Response scalar is not canonical")
+        })?;
+
+        Ok(DLogProof { t, s })
+    }
+
+    /// Recomputes the Fiat-Shamir challenge for every proof in a batch.
+    fn batch_challenges(
+        items: &[(&str, u32, ProjectivePoint, ProjectivePoint, &DLogProof)],
+    ) -> Result<Vec<Scalar>, Error> {
+        items
+            .iter()
+            .map(|(sid, pid, y, base_point, proof)| {
+                Self::hash_points(sid, *pid, &[*base_point, *y, proof.t])
+            })
+            .collect()
+    }
+}
+
+/// `serde` support for `DLogProof`, built on the canonical `to_bytes`/`from_bytes` encoding.
+/// Enabled via the `serde` feature so proofs can be embedded in larger wire formats without
+/// forcing the dependency on every consumer of this crate.
+#[cfg(feature = "serde")]
+mod dlog_serde {
+    use super::DLogProof;
+    use serde::de::Error as DeError;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    impl Serialize for DLogProof {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_bytes(&self.to_bytes())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for DLogProof {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let bytes = <Vec<u8>>::deserialize(deserializer)?;
+            DLogProof::from_bytes(&bytes).map_err(DeError::custom)
+        }
+    }
+}
+
+/// Trait that defines a Discrete Logarithm Equality (DLEQ) proof, proving that a single
+/// secret `x` is simultaneously the discrete log of two points under two different bases:
+/// `y1 = base1 * x` and `y2 = base2 * x`.
+pub trait DiscreteLogEqualityProof {
+    /// Generates a DLEQ proof.
+    fn prove(
+        sid: &str,
+        pid: u32,
+        x: &Scalar,
+        base1: &ProjectivePoint,
+        y1: &ProjectivePoint,
+        base2: &ProjectivePoint,
+        y2: &ProjectivePoint,
+    ) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Verifies a DLEQ proof.
+    fn verify(
+        &self,
+        sid: &str,
+        pid: u32,
+        base1: &ProjectivePoint,
+        y1: &ProjectivePoint,
+        base2: &ProjectivePoint,
+        y2: &ProjectivePoint,
+    ) -> Result<bool, Error>;
+}
+
+/// Represents a Discrete Logarithm Equality (DLEQ) proof.
+///
+/// Proves that the same secret scalar `x` satisfies `y1 = base1 * x` and `y2 = base2 * x`
+/// without revealing `x`. Useful for verifiable ElGamal decryption and publicly verifiable
+/// secret sharing, where a single base Schnorr proof cannot express the cross-base equality.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DLEqProof {
+    /// The commitment value t1 = base1 * r
+    pub t1: ProjectivePoint,
+    /// The commitment value t2 = base2 * r
+    pub t2: ProjectivePoint,
+    /// The response value s
+    pub s: Scalar,
+}
+
+impl DiscreteLogEqualityProof for DLEqProof {
+    /// Generates a DLEQ proof that `x` is the discrete log of `y1` under `base1` and of
+    /// `y2` under `base2`.
+    /// # Arguments
+    /// * `sid` - The session ID
+    /// * `pid` - The participant ID
+    /// * `x` - The shared secret scalar value
+    /// * `base1` - The first base point
+    /// * `y1` - `base1 * x`
+    /// * `base2` - The second base point
+    /// * `y2` - `base2 * x`
+    /// # Returns
+    /// A DLEQ proof
+    /// # Example
+    /// ```rust
+    /// use k256::{ProjectivePoint, Scalar};
+    /// use k256::elliptic_curve::Field;
+    /// use rand_core::OsRng;
+    /// use schnorr_zk_dlog::dlog::{DiscreteLogEqualityProof, DLEqProof};
+    /// let sid = "test_session";
+    /// let pid = 1;
+    /// let x = Scalar::random(&mut OsRng);
+    /// let base1 = ProjectivePoint::GENERATOR;
+    /// let base2 = ProjectivePoint::GENERATOR * Scalar::from(7u64);
+    /// let y1 = base1 * x;
+    /// let y2 = base2 * x;
+    /// let proof = DLEqProof::prove(sid, pid, &x, &base1, &y1, &base2, &y2).expect("Proof generation failed");
+    /// ```
+    fn prove(
+        sid: &str,
+        pid: u32,
+        x: &Scalar,
+        base1: &ProjectivePoint,
+        y1: &ProjectivePoint,
+        base2: &ProjectivePoint,
+        y2: &ProjectivePoint,
+    ) -> Result<Self, Error> {
+        let r = Scalar::random(&mut OsRng);
+        let t1 = base1 * &r;
+        let t2 = base2 * &r;
+
+        let c = DLogProof::hash_points(sid, pid, &[*base1, *base2, *y1, *y2, t1, t2])?;
+        let s = r + (c * x);
+
+        Ok(DLEqProof { t1, t2, s })
+    }
+
+    /// Verifies a DLEQ proof.
+    /// # Arguments
+    /// * `sid` - The session ID
+    /// * `pid` - The participant ID
+    /// * `base1` - The first base point
+    /// * `y1` - `base1 * x`
+    /// * `base2` - The second base point
+    /// * `y2` - `base2 * x`
+    /// # Returns
+    /// A boolean indicating if the proof is valid
+    /// # Example
+    /// ```rust
+    /// use k256::{ProjectivePoint, Scalar};
+    /// use k256::elliptic_curve::Field;
+    /// use rand_core::OsRng;
+    /// use schnorr_zk_dlog::dlog::{DiscreteLogEqualityProof, DLEqProof};
+    /// let sid = "test_session";
+    /// let pid = 1;
+    /// let x = Scalar::random(&mut OsRng);
+    /// let base1 = ProjectivePoint::GENERATOR;
+    /// let base2 = ProjectivePoint::GENERATOR * Scalar::from(7u64);
+    /// let y1 = base1 * x;
+    /// let y2 = base2 * x;
+    /// let proof = DLEqProof::prove(sid, pid, &x, &base1, &y1, &base2, &y2).expect("Proof generation failed");
+    /// let result = proof.verify(sid, pid, &base1, &y1, &base2, &y2).expect("Verification failed");
+    /// assert!(result, "Proof should verify");
+    /// ```
+    fn verify(
+        &self,
+        sid: &str,
+        pid: u32,
+        base1: &ProjectivePoint,
+        y1: &ProjectivePoint,
+        base2: &ProjectivePoint,
+        y2: &ProjectivePoint,
+    ) -> Result<bool, Error> {
+        let c = DLogProof::hash_points(sid, pid, &[*base1, *base2, *y1, *y2, self.t1, self.t2])?;
+        let lhs1 = base1 * &self.s;
+        let rhs1 = self.t1 + y1 * &c;
+        let lhs2 = base2 * &self.s;
+        let rhs2 = self.t2 + y2 * &c;
+        Ok(lhs1 == rhs1 && lhs2 == rhs2)
+    }
+}
+
+impl DLEqProof {
+    /// Creates a new DLEQ proof.
+    /// # Arguments
+    /// * `t1` - The commitment value t1
+    /// * `t2` - The commitment value t2
+    /// * `s` - The response value s
+    /// # Returns
+    /// A new DLEQ proof
+    pub fn new(t1: ProjectivePoint, t2: ProjectivePoint, s: Scalar) -> Self {
+        DLEqProof { t1, t2, s }
+    }
 }
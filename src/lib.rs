@@ -0,0 +1,4 @@
+pub mod dlog;
+pub mod pvss;
+pub mod sigma;
+pub mod transcript;
@@ -0,0 +1,215 @@
+//! Publicly Verifiable Secret Sharing (PVSS), Schoenmakers-style, built on [`crate::dlog::DLEqProof`].
+//!
+//! A dealer splits a secret exponent among `n` participants behind a degree-`t-1`
+//! polynomial, so that any `t` of them can reconstruct it while fewer than `t` learn
+//! nothing. What makes it *publicly* verifiable is that every encrypted share ships with a
+//! DLEQ proof anyone can check against the dealer's public commitments, without needing a
+//! participant's private key: the scheme does not have to trust the dealer.
+//!
+//! The reconstructed `Secret` is the group element `G * secret` rather than the bare
+//! scalar, since reconstruction is done "in the exponent" by combining participants'
+//! decrypted shares (themselves group elements) with Lagrange coefficients: no party other
+//! than the original dealer ever needs to know the scalar directly.
+
+use k256::{elliptic_curve::Field, ProjectivePoint, Scalar};
+use rand_core::OsRng;
+use std::io::Error;
+
+use crate::dlog::{DLEqProof, DiscreteLogEqualityProof};
+
+/// Domain-separation session id for the DLEQ proofs attached to each encrypted share.
+const PVSS_SID: &str = "dlogzkp/pvss/v1";
+
+/// The reconstructed secret: `G * secret`, recovered via exponent-domain interpolation.
+pub type Secret = ProjectivePoint;
+
+/// Everything a dealer publishes for a PVSS dealing.
+#[derive(Debug, Clone)]
+pub struct Dealing {
+    /// `commitments[j] = G * coeff_j` for the polynomial's coefficients, coefficient 0
+    /// being the dealt secret itself; these let anyone recompute `X_i = G * p(i)` for
+    /// participant `i`.
+    pub commitments: Vec<ProjectivePoint>,
+    /// `encrypted_shares[i] = pubkeys[i] * p(i + 1)`, participant `i`'s share encrypted
+    /// under their own public key (participants are 1-indexed internally, `X_1` being the
+    /// first share).
+    pub encrypted_shares: Vec<ProjectivePoint>,
+    /// `proofs[i]` is a DLEQ proof that `log_G(X_{i+1}) == log_{pubkeys[i]}(encrypted_shares[i])`,
+    /// i.e. that the share was encrypted correctly, without revealing `p(i + 1)`.
+    pub proofs: Vec<DLEqProof>,
+}
+
+/// Splits `secret` among `pubkeys.len()` participants behind a degree-`(threshold - 1)`
+/// polynomial, so that any `threshold` of them can later reconstruct it.
+/// # Example
+/// ```rust
+/// use k256::{elliptic_curve::Field, ProjectivePoint, Scalar};
+/// use rand_core::OsRng;
+/// use schnorr_zk_dlog::pvss;
+///
+/// let secret = Scalar::random(&mut OsRng);
+/// let secret_keys: Vec<Scalar> = (0..5).map(|_| Scalar::random(&mut OsRng)).collect();
+/// let pubkeys: Vec<ProjectivePoint> = secret_keys
+///     .iter()
+///     .map(|sk| ProjectivePoint::GENERATOR * sk)
+///     .collect();
+///
+/// let dealing = pvss::deal(&secret, &pubkeys, 3).expect("Dealing failed");
+/// let valid = pvss::verify_shares(
+///     &dealing.commitments,
+///     &pubkeys,
+///     &dealing.encrypted_shares,
+///     &dealing.proofs,
+/// )
+/// .expect("Verification failed");
+/// assert!(valid, "Honestly dealt shares should verify");
+/// ```
+pub fn deal(secret: &Scalar, pubkeys: &[ProjectivePoint], threshold: usize) -> Result<Dealing, Error> {
+    if threshold == 0 || threshold > pubkeys.len() {
+        return Err(Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "threshold must be between 1 and the number of participants",
+        ));
+    }
+
+    let mut coeffs = Vec::with_capacity(threshold);
+    coeffs.push(*secret);
+    for _ in 1..threshold {
+        coeffs.push(Scalar::random(&mut OsRng));
+    }
+
+    let generator = ProjectivePoint::GENERATOR;
+    let commitments: Vec<ProjectivePoint> = coeffs.iter().map(|coeff| generator * coeff).collect();
+
+    let mut encrypted_shares = Vec::with_capacity(pubkeys.len());
+    let mut proofs = Vec::with_capacity(pubkeys.len());
+
+    for (offset, pubkey) in pubkeys.iter().enumerate() {
+        let index = offset + 1;
+        let share = evaluate_polynomial(&coeffs, Scalar::from(index as u64));
+        let x_i = evaluate_commitments(&commitments, index);
+        let y_i = pubkey * share;
+
+        let proof = DLEqProof::prove(PVSS_SID, index as u32, &share, &generator, &x_i, pubkey, &y_i)?;
+
+        encrypted_shares.push(y_i);
+        proofs.push(proof);
+    }
+
+    Ok(Dealing {
+        commitments,
+        encrypted_shares,
+        proofs,
+    })
+}
+
+/// Verifies every encrypted share against the dealer's public commitments, without needing
+/// any participant's private key.
+/// # Returns
+/// `Ok(true)` iff every proof is valid and ties its share to the commitments' `X_i`.
+pub fn verify_shares(
+    commitments: &[ProjectivePoint],
+    pubkeys: &[ProjectivePoint],
+    encrypted_shares: &[ProjectivePoint],
+    proofs: &[DLEqProof],
+) -> Result<bool, Error> {
+    if pubkeys.len() != encrypted_shares.len() || pubkeys.len() != proofs.len() {
+        return Err(Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "pubkeys, encrypted_shares, and proofs must have the same length",
+        ));
+    }
+
+    let generator = ProjectivePoint::GENERATOR;
+    for (offset, ((pubkey, share), proof)) in
+        pubkeys.iter().zip(encrypted_shares).zip(proofs).enumerate()
+    {
+        let index = offset + 1;
+        let x_i = evaluate_commitments(commitments, index);
+        let valid = proof.verify(PVSS_SID, index as u32, &generator, &x_i, pubkey, share)?;
+        if !valid {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+/// Reconstructs `G * secret` from `threshold`-or-more participants' decrypted shares
+/// `G * p(index)`, via Lagrange interpolation in the exponent.
+///
+/// `indices` and `decrypted_shares` must be the same length and pair up positionally; any
+/// `threshold` (or more) of the `n` dealt shares suffice, in any order.
+pub fn reconstruct(indices: &[usize], decrypted_shares: &[ProjectivePoint]) -> Result<Secret, Error> {
+    if indices.len() != decrypted_shares.len() {
+        return Err(Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "indices and decrypted_shares must have the same length",
+        ));
+    }
+    if indices.is_empty() {
+        return Err(Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "at least one share is required to reconstruct the secret",
+        ));
+    }
+
+    let mut secret = ProjectivePoint::IDENTITY;
+    for (&index, share) in indices.iter().zip(decrypted_shares) {
+        let coefficient = lagrange_coefficient_at_zero(indices, index)?;
+        secret += share * coefficient;
+    }
+    Ok(secret)
+}
+
+/// Evaluates the dealer's polynomial `p(x) = coeffs[0] + coeffs[1]*x + ...` via Horner's method.
+fn evaluate_polynomial(coeffs: &[Scalar], x: Scalar) -> Scalar {
+    let mut result = Scalar::ZERO;
+    for coeff in coeffs.iter().rev() {
+        result = result * x + coeff;
+    }
+    result
+}
+
+/// Recomputes `X_i = G * p(index)` from the dealer's commitments via `sum_j C_j * index^j`,
+/// without knowing the polynomial's coefficients.
+fn evaluate_commitments(commitments: &[ProjectivePoint], index: usize) -> ProjectivePoint {
+    let x = Scalar::from(index as u64);
+    let mut power = Scalar::ONE;
+    let mut acc = ProjectivePoint::IDENTITY;
+    for commitment in commitments {
+        acc += commitment * power;
+        power *= x;
+    }
+    acc
+}
+
+/// Computes the Lagrange coefficient `lambda_index(0) = prod_{j != index} x_j / (x_j - x_index)`
+/// for interpolating the polynomial's value at zero from the given set of indices.
+fn lagrange_coefficient_at_zero(indices: &[usize], index: usize) -> Result<Scalar, Error> {
+    let x_index = Scalar::from(index as u64);
+    let mut numerator = Scalar::ONE;
+    let mut denominator = Scalar::ONE;
+
+    // Skip only the first occurrence of `index`: a repeated index then contributes a zero
+    // factor to the denominator and is rejected below.
+    let mut skipped_self = false;
+    for &j in indices {
+        if j == index && !skipped_self {
+            skipped_self = true;
+            continue;
+        }
+        let x_j = Scalar::from(j as u64);
+        numerator *= x_j;
+        denominator *= x_j - x_index;
+    }
+
+    let denominator_inv: Scalar = Option::from(denominator.invert()).ok_or_else(|| {
+        Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "duplicate participant index in reconstruction set",
+        )
+    })?;
+
+    Ok(numerator * denominator_inv)
+}
@@ -0,0 +1,172 @@
+//! A declarative-macro compiler for sigma protocols over arbitrary linear relations.
+//!
+//! `DLogProof` and `DLEqProof` each hardcode one shape of relation (`y = base*x` and
+//! `y1 = base1*x, y2 = base2*x`). [`sigma_protocol!`] generalizes both: given a conjunction
+//! of equations `Point_j = sum_k base_jk * x_k` sharing secret scalars across equations, it
+//! expands into a `Bases`/`Points`/`Secrets` statement, a compact `Proof` struct, and
+//! `prove`/`verify` functions, all built on the labeled [`crate::transcript::Transcript`].
+
+/// Compiles a sigma protocol for a conjunction of linear equations over the group.
+///
+/// # Syntax
+/// ```text
+/// sigma_protocol! {
+///     name: StatementName,
+///     secrets: [x, r],
+///     bases: [G, H],
+///     equations: [
+///         A = [(G, x), (H, r)],
+///         B = [(G, x)],
+///     ],
+/// }
+/// ```
+/// expands to a module `StatementName` containing:
+/// * `Bases { G, H }` and `Points { A, B }` — the public statement
+/// * `Secrets { x, r }` — the witness
+/// * `Proof { c, x, r }` — the compact `(challenge, responses)` proof
+/// * `prove(sid, pid, &Bases, &Points, &Secrets) -> Result<Proof, Error>`
+/// * `verify(sid, pid, &Bases, &Points, &Proof) -> Result<bool, Error>`
+///
+/// Every secret variable gets a fresh blinding nonce; every equation's announcement is the
+/// matching linear combination of those nonces; all bases, public points, and announcements
+/// are absorbed into one transcript that squeezes a single shared challenge `c`; and each
+/// response is `s_k = nonce_k + c*x_k`. Verification recomputes each announcement as
+/// `(sum base_jk*s_k) - Point_j*c` and accepts iff the transcript re-derives the same `c`.
+///
+/// Each base named in an equation must also appear in `bases:`; declaring it once in
+/// `bases:` and reusing its identifier across equations is what ties shared secrets (and
+/// shared bases) together.
+/// # Example
+/// ```rust
+/// use k256::{ProjectivePoint, Scalar};
+/// use k256::elliptic_curve::Field;
+/// use rand_core::OsRng;
+/// use schnorr_zk_dlog::sigma_protocol;
+///
+/// sigma_protocol! {
+///     name: PedersenAndDLog,
+///     secrets: [x, r],
+///     bases: [g, h],
+///     equations: [
+///         a = [(g, x), (h, r)],
+///         b = [(g, x)],
+///     ],
+/// }
+///
+/// let g = ProjectivePoint::GENERATOR;
+/// let h = ProjectivePoint::GENERATOR * Scalar::from(7u64);
+/// let x = Scalar::random(&mut OsRng);
+/// let r = Scalar::random(&mut OsRng);
+/// let bases = PedersenAndDLog::Bases { g, h };
+/// let points = PedersenAndDLog::Points {
+///     a: g * x + h * r,
+///     b: g * x,
+/// };
+/// let secrets = PedersenAndDLog::Secrets { x, r };
+///
+/// let proof = PedersenAndDLog::prove("sid", 1, &bases, &points, &secrets)
+///     .expect("Proof generation failed");
+/// let result = PedersenAndDLog::verify("sid", 1, &bases, &points, &proof)
+///     .expect("Verification failed");
+/// assert!(result, "Proof should verify");
+/// ```
+#[macro_export]
+macro_rules! sigma_protocol {
+    (
+        name: $name:ident,
+        secrets: [ $($secret:ident),+ $(,)? ],
+        bases: [ $($base:ident),+ $(,)? ],
+        equations: [
+            $( $point:ident = [ $( ($eq_base:ident, $eq_var:ident) ),+ $(,)? ] ),+ $(,)?
+        ] $(,)?
+    ) => {
+        #[allow(non_snake_case)]
+        pub mod $name {
+            use k256::{elliptic_curve::Field, ProjectivePoint, Scalar};
+            use rand_core::OsRng;
+            use std::io::Error;
+            use $crate::transcript::Transcript;
+
+            /// The public bases shared across this statement's equations.
+            #[derive(Debug, Clone, Copy)]
+            pub struct Bases {
+                $( pub $base: ProjectivePoint, )+
+            }
+
+            /// The public points this statement proves are linear combinations of `Bases`.
+            #[derive(Debug, Clone, Copy)]
+            pub struct Points {
+                $( pub $point: ProjectivePoint, )+
+            }
+
+            /// The witness: one scalar per secret shared across the statement's equations.
+            #[derive(Debug, Clone, Copy)]
+            pub struct Secrets {
+                $( pub $secret: Scalar, )+
+            }
+
+            /// Compact sigma-protocol proof: the shared challenge plus one response per secret.
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            pub struct Proof {
+                pub c: Scalar,
+                $( pub $secret: Scalar, )+
+            }
+
+            fn transcript(sid: &str, pid: u32, bases: &Bases, points: &Points) -> Transcript {
+                let mut transcript = Transcript::new(concat!("dlogzkp/sigma/", stringify!($name)));
+                transcript.append_message("sid", sid.as_bytes());
+                transcript.append_message("pid", &pid.to_be_bytes());
+                $( transcript.append_point(stringify!($base), &bases.$base); )+
+                $( transcript.append_point(stringify!($point), &points.$point); )+
+                transcript
+            }
+
+            /// Proves the conjunction of linear equations declared for this statement.
+            pub fn prove(
+                sid: &str,
+                pid: u32,
+                bases: &Bases,
+                points: &Points,
+                secrets: &Secrets,
+            ) -> Result<Proof, Error> {
+                let nonces = Secrets {
+                    $( $secret: Scalar::random(&mut OsRng), )+
+                };
+
+                let mut transcript = transcript(sid, pid, bases, points);
+                $(
+                    let announcement =
+                        $( bases.$eq_base * &nonces.$eq_var + )+ ProjectivePoint::IDENTITY;
+                    transcript.append_point(concat!("t_", stringify!($point)), &announcement);
+                )+
+
+                let c = transcript.challenge_scalar("c")?;
+
+                Ok(Proof {
+                    c,
+                    $( $secret: nonces.$secret + c * secrets.$secret, )+
+                })
+            }
+
+            /// Verifies a proof produced by `prove` against the given public statement.
+            pub fn verify(
+                sid: &str,
+                pid: u32,
+                bases: &Bases,
+                points: &Points,
+                proof: &Proof,
+            ) -> Result<bool, Error> {
+                let mut transcript = transcript(sid, pid, bases, points);
+                $(
+                    let recomputed_announcement =
+                        $( bases.$eq_base * &proof.$eq_var + )+ ProjectivePoint::IDENTITY
+                        - points.$point * &proof.c;
+                    transcript.append_point(concat!("t_", stringify!($point)), &recomputed_announcement);
+                )+
+
+                let c = transcript.challenge_scalar("c")?;
+                Ok(c == proof.c)
+            }
+        }
+    };
+}
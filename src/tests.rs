@@ -178,6 +178,433 @@ mod random_scalar_tests {
     }
 }
 
+mod batch_verify_tests {
+    use super::*;
+
+    fn make_proof(sid: &str, pid: u32) -> (ProjectivePoint, ProjectivePoint, DLogProof) {
+        let x = Scalar::random(&mut OsRng);
+        let base_point = ProjectivePoint::GENERATOR;
+        let y = base_point * x;
+        let proof = DLogProof::prove(sid, pid, &x, &y, &base_point).expect("Proof generation failed");
+        (y, base_point, proof)
+    }
+
+    #[test]
+    fn test_verify_batch_all_valid() {
+        // Test that a batch of valid proofs verifies together.
+        let (y1, base1, proof1) = make_proof("sid", 1);
+        let (y2, base2, proof2) = make_proof("sid", 2);
+        let (y3, base3, proof3) = make_proof("sid", 3);
+
+        let items = vec![
+            ("sid", 1, y1, base1, &proof1),
+            ("sid", 2, y2, base2, &proof2),
+            ("sid", 3, y3, base3, &proof3),
+        ];
+
+        let result = DLogProof::verify_batch(&items).expect("Batch verification failed");
+        assert!(result, "Batch of valid proofs should verify");
+    }
+
+    #[test]
+    fn test_verify_batch_empty() {
+        // An empty batch trivially verifies.
+        let items: Vec<(&str, u32, ProjectivePoint, ProjectivePoint, &DLogProof)> = vec![];
+        let result = DLogProof::verify_batch(&items).expect("Batch verification failed");
+        assert!(result, "Empty batch should verify");
+    }
+
+    #[test]
+    fn test_verify_batch_detects_tampered_proof() {
+        // Test that a single tampered proof causes the whole batch to fail.
+        let (y1, base1, proof1) = make_proof("sid", 1);
+        let (y2, base2, proof2) = make_proof("sid", 2);
+        let tampered = DLogProof {
+            t: proof2.t,
+            s: proof2.s + Scalar::ONE,
+        };
+
+        let items = vec![
+            ("sid", 1, y1, base1, &proof1),
+            ("sid", 2, y2, base2, &tampered),
+        ];
+
+        let result = DLogProof::verify_batch(&items).expect("Batch verification failed");
+        assert!(!result, "Batch containing a tampered proof should not verify");
+
+        let bad_index = DLogProof::first_invalid_index(&items)
+            .expect("Diagnostics failed")
+            .expect("Expected a failing index");
+        assert_eq!(bad_index, 1, "The tampered proof should be reported as the failing index");
+    }
+}
+
+mod pvss_tests {
+    use super::*;
+    use schnorr_zk_dlog::pvss;
+
+    fn make_participants(n: usize) -> (Vec<Scalar>, Vec<ProjectivePoint>) {
+        let secret_keys: Vec<Scalar> = (0..n).map(|_| Scalar::random(&mut OsRng)).collect();
+        let pubkeys: Vec<ProjectivePoint> = secret_keys
+            .iter()
+            .map(|sk| ProjectivePoint::GENERATOR * sk)
+            .collect();
+        (secret_keys, pubkeys)
+    }
+
+    #[test]
+    fn test_deal_verify_reconstruct_cycle() {
+        // A full honest deal/verify/reconstruct cycle should recover G * secret from any
+        // threshold-sized subset of participants.
+        let (secret_keys, pubkeys) = make_participants(5);
+        let secret = Scalar::random(&mut OsRng);
+        let threshold = 3;
+
+        let dealing = pvss::deal(&secret, &pubkeys, threshold).expect("Dealing failed");
+
+        let valid = pvss::verify_shares(
+            &dealing.commitments,
+            &pubkeys,
+            &dealing.encrypted_shares,
+            &dealing.proofs,
+        )
+        .expect("Verification failed");
+        assert!(valid, "Honestly dealt shares should verify");
+
+        // Each participant decrypts their own share: Y_i * sk_i^-1 == G * p(i).
+        let indices = vec![1usize, 2, 4];
+        let decrypted_shares: Vec<ProjectivePoint> = indices
+            .iter()
+            .map(|&i| {
+                let sk_inv: Scalar = Option::from(secret_keys[i - 1].invert())
+                    .expect("Secret key should be invertible");
+                dealing.encrypted_shares[i - 1] * sk_inv
+            })
+            .collect();
+
+        let reconstructed = pvss::reconstruct(&indices, &decrypted_shares)
+            .expect("Reconstruction failed");
+        assert_eq!(
+            reconstructed,
+            ProjectivePoint::GENERATOR * secret,
+            "Reconstructed secret should equal G * secret"
+        );
+    }
+
+    #[test]
+    fn test_verify_shares_detects_tampered_share() {
+        // Tampering with a single encrypted share should be caught by verify_shares.
+        let (_secret_keys, pubkeys) = make_participants(4);
+        let secret = Scalar::random(&mut OsRng);
+        let threshold = 2;
+
+        let mut dealing = pvss::deal(&secret, &pubkeys, threshold).expect("Dealing failed");
+
+        dealing.encrypted_shares[1] += ProjectivePoint::GENERATOR;
+
+        let valid = pvss::verify_shares(
+            &dealing.commitments,
+            &pubkeys,
+            &dealing.encrypted_shares,
+            &dealing.proofs,
+        )
+        .expect("Verification failed");
+        assert!(!valid, "A tampered share should fail verification");
+    }
+
+    #[test]
+    fn test_deal_rejects_invalid_threshold() {
+        let (_secret_keys, pubkeys) = make_participants(3);
+        let secret = Scalar::random(&mut OsRng);
+
+        assert!(pvss::deal(&secret, &pubkeys, 0).is_err(), "Threshold of zero should be rejected");
+        assert!(
+            pvss::deal(&secret, &pubkeys, pubkeys.len() + 1).is_err(),
+            "Threshold larger than the participant count should be rejected"
+        );
+    }
+}
+
+mod serialization_tests {
+    use super::*;
+
+    #[test]
+    fn test_to_bytes_has_expected_length() {
+        let x = Scalar::random(&mut OsRng);
+        let base_point = ProjectivePoint::GENERATOR;
+        let y = base_point * x;
+        let proof = DLogProof::prove("sid", 1, &x, &y, &base_point).expect("Proof generation failed");
+        assert_eq!(proof.to_bytes().len(), DLogProof::BYTES_LEN);
+    }
+
+    #[test]
+    fn test_round_trip_encode_decode_preserves_proof() {
+        // Encoding then decoding should reproduce the original proof exactly.
+        let x = Scalar::random(&mut OsRng);
+        let base_point = ProjectivePoint::GENERATOR;
+        let y = base_point * x;
+        let proof = DLogProof::prove("sid", 1, &x, &y, &base_point).expect("Proof generation failed");
+
+        let decoded = DLogProof::from_bytes(&proof.to_bytes()).expect("Decoding failed");
+        assert_eq!(proof, decoded, "Decoded proof should equal the original");
+    }
+
+    #[test]
+    fn test_round_tripped_proof_still_verifies() {
+        // A proof that survived an encode/decode round trip should still verify.
+        let x = Scalar::random(&mut OsRng);
+        let base_point = ProjectivePoint::GENERATOR;
+        let y = base_point * x;
+        let proof = DLogProof::prove("sid", 1, &x, &y, &base_point).expect("Proof generation failed");
+
+        let decoded = DLogProof::from_bytes(&proof.to_bytes()).expect("Decoding failed");
+        let result = decoded.verify("sid", 1, &y, &base_point).expect("Verification failed");
+        assert!(result, "Round-tripped proof should still verify");
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_wrong_length() {
+        let too_short = vec![0u8; DLogProof::BYTES_LEN - 1];
+        assert!(DLogProof::from_bytes(&too_short).is_err(), "Short input should be rejected");
+
+        let too_long = vec![0u8; DLogProof::BYTES_LEN + 1];
+        assert!(DLogProof::from_bytes(&too_long).is_err(), "Long input should be rejected");
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_point_not_on_curve() {
+        let mut bytes = [0u8; DLogProof::BYTES_LEN];
+        // A compressed-point prefix of 0x02 with an all-zero x-coordinate is not a valid
+        // point on the secp256k1 curve.
+        bytes[0] = 0x02;
+        assert!(DLogProof::from_bytes(&bytes).is_err(), "Invalid curve point should be rejected");
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_identity_commitment() {
+        let x = Scalar::random(&mut OsRng);
+        let base_point = ProjectivePoint::GENERATOR;
+        let y = base_point * x;
+        let proof = DLogProof::prove("sid", 1, &x, &y, &base_point).expect("Proof generation failed");
+
+        let mut bytes = proof.to_bytes();
+        // SEC1's single-byte encoding of the identity point.
+        bytes[0] = 0x00;
+        for byte in bytes[1..33].iter_mut() {
+            *byte = 0;
+        }
+        assert!(DLogProof::from_bytes(&bytes).is_err(), "Identity commitment should be rejected");
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_non_canonical_scalar() {
+        let x = Scalar::random(&mut OsRng);
+        let base_point = ProjectivePoint::GENERATOR;
+        let y = base_point * x;
+        let proof = DLogProof::prove("sid", 1, &x, &y, &base_point).expect("Proof generation failed");
+
+        let mut bytes = proof.to_bytes();
+        // All-0xff is far larger than the secp256k1 group order.
+        for byte in bytes[33..].iter_mut() {
+            *byte = 0xff;
+        }
+        assert!(DLogProof::from_bytes(&bytes).is_err(), "Non-canonical scalar should be rejected");
+    }
+}
+
+mod sigma_protocol_tests {
+    use super::*;
+    use schnorr_zk_dlog::sigma_protocol;
+
+    sigma_protocol! {
+        name: PedersenAndDLog,
+        secrets: [x, r],
+        bases: [g, h],
+        equations: [
+            a = [(g, x), (h, r)],
+            b = [(g, x)],
+        ],
+    }
+
+    #[test]
+    fn test_sigma_protocol_prove_and_verify() {
+        // Test that the macro-generated proof round-trips for a two-equation conjunction
+        // sharing the secret `x` between them.
+        let g = ProjectivePoint::GENERATOR;
+        let h = ProjectivePoint::GENERATOR * Scalar::from(7u64);
+        let x = Scalar::random(&mut OsRng);
+        let r = Scalar::random(&mut OsRng);
+
+        let bases = PedersenAndDLog::Bases { g, h };
+        let points = PedersenAndDLog::Points {
+            a: g * x + h * r,
+            b: g * x,
+        };
+        let secrets = PedersenAndDLog::Secrets { x, r };
+
+        let proof = PedersenAndDLog::prove("sid", 1, &bases, &points, &secrets)
+            .expect("Proof generation failed");
+        let result = PedersenAndDLog::verify("sid", 1, &bases, &points, &proof)
+            .expect("Verification failed");
+        assert!(result, "Valid sigma-protocol proof should verify");
+    }
+
+    #[test]
+    fn test_sigma_protocol_rejects_inconsistent_witness() {
+        // If `b` is not actually `g*x` for the same `x` used in `a`, the shared-secret
+        // constraint should be caught.
+        let g = ProjectivePoint::GENERATOR;
+        let h = ProjectivePoint::GENERATOR * Scalar::from(7u64);
+        let x = Scalar::random(&mut OsRng);
+        let r = Scalar::random(&mut OsRng);
+        let wrong_x = Scalar::random(&mut OsRng);
+
+        let bases = PedersenAndDLog::Bases { g, h };
+        let points = PedersenAndDLog::Points {
+            a: g * x + h * r,
+            b: g * wrong_x,
+        };
+        let secrets = PedersenAndDLog::Secrets { x, r };
+
+        let proof = PedersenAndDLog::prove("sid", 1, &bases, &points, &secrets)
+            .expect("Proof generation failed");
+        let result = PedersenAndDLog::verify("sid", 1, &bases, &points, &proof)
+            .expect("Verification failed");
+        assert!(!result, "Proof should not verify against an inconsistent statement");
+    }
+}
+
+mod transcript_tests {
+    use super::*;
+    use schnorr_zk_dlog::transcript::Transcript;
+
+    #[test]
+    fn test_challenge_scalar_is_deterministic() {
+        // Squeezing the same label from the same absorbed history must be deterministic.
+        let mut t1 = Transcript::new("test/v1");
+        t1.append_message("sid", b"test_session");
+        t1.append_point("base", &ProjectivePoint::GENERATOR);
+
+        let mut t2 = Transcript::new("test/v1");
+        t2.append_message("sid", b"test_session");
+        t2.append_point("base", &ProjectivePoint::GENERATOR);
+
+        let c1 = t1.challenge_scalar("c").expect("Challenge derivation failed");
+        let c2 = t2.challenge_scalar("c").expect("Challenge derivation failed");
+        assert_eq!(c1, c2, "Same transcript history should yield the same challenge");
+    }
+
+    #[test]
+    fn test_challenge_scalar_domain_separated_by_protocol_label() {
+        // Two transcripts seeded with different protocol labels must not collide.
+        let mut t1 = Transcript::new("test/v1");
+        t1.append_point("base", &ProjectivePoint::GENERATOR);
+
+        let mut t2 = Transcript::new("test/v2");
+        t2.append_point("base", &ProjectivePoint::GENERATOR);
+
+        let c1 = t1.challenge_scalar("c").expect("Challenge derivation failed");
+        let c2 = t2.challenge_scalar("c").expect("Challenge derivation failed");
+        assert_ne!(c1, c2, "Different protocol labels should produce different challenges");
+    }
+
+    #[test]
+    fn test_challenge_scalar_avoids_concatenation_ambiguity() {
+        // Absorbing ("ab", "c") should not hash the same as ("a", "bc") thanks to length prefixing.
+        let mut t1 = Transcript::new("test/v1");
+        t1.append_message("ab", b"c");
+
+        let mut t2 = Transcript::new("test/v1");
+        t2.append_message("a", b"bc");
+
+        let c1 = t1.challenge_scalar("x").expect("Challenge derivation failed");
+        let c2 = t2.challenge_scalar("x").expect("Challenge derivation failed");
+        assert_ne!(c1, c2, "Length-prefixed labels should disambiguate concatenation");
+    }
+
+    #[test]
+    fn test_dlog_proof_with_transcript_round_trips() {
+        // The transcript-backed DLOG proof path should behave like the original one.
+        let sid = "test_session";
+        let pid = 1;
+        let x = Scalar::random(&mut OsRng);
+        let base_point = ProjectivePoint::GENERATOR;
+        let y = base_point * x;
+
+        let proof = DLogProof::prove_with_transcript(sid, pid, &x, &y, &base_point)
+            .expect("Proof generation failed");
+        let result = proof
+            .verify_with_transcript(sid, pid, &y, &base_point)
+            .expect("Verification failed");
+        assert!(result, "Transcript-backed proof should verify");
+    }
+}
+
+mod dleq_prove_verify_tests {
+    use super::*;
+    use schnorr_zk_dlog::dlog::{DLEqProof, DiscreteLogEqualityProof};
+
+    #[test]
+    fn test_basic_dleq_prove_and_verify() {
+        // Test that a DLEQ proof can be generated and verified across two different bases.
+        let sid = "test_session";
+        let pid = 1;
+        let x = Scalar::random(&mut OsRng);
+        let base1 = ProjectivePoint::GENERATOR;
+        let base2 = ProjectivePoint::GENERATOR * Scalar::from(7u64);
+        let y1 = base1 * x;
+        let y2 = base2 * x;
+
+        let proof = DLEqProof::prove(sid, pid, &x, &base1, &y1, &base2, &y2)
+            .expect("Proof generation failed");
+        let result = proof
+            .verify(sid, pid, &base1, &y1, &base2, &y2)
+            .expect("Verification failed");
+        assert!(result, "Valid DLEQ proof should verify");
+    }
+
+    #[test]
+    fn test_dleq_rejects_unequal_discrete_logs() {
+        // Test that a DLEQ proof does not verify when the two discrete logs differ.
+        let sid = "test_session";
+        let pid = 1;
+        let x1 = Scalar::random(&mut OsRng);
+        let x2 = Scalar::random(&mut OsRng);
+        let base1 = ProjectivePoint::GENERATOR;
+        let base2 = ProjectivePoint::GENERATOR * Scalar::from(7u64);
+        let y1 = base1 * x1;
+        let y2 = base2 * x2;
+
+        // A proof built honestly for x1 should not verify against y2 derived from x2.
+        let proof = DLEqProof::prove(sid, pid, &x1, &base1, &y1, &base2, &y2)
+            .expect("Proof generation failed");
+        let result = proof
+            .verify(sid, pid, &base1, &y1, &base2, &y2)
+            .expect("Verification failed");
+        assert!(!result, "DLEQ proof should not verify for unequal discrete logs");
+    }
+
+    #[test]
+    fn test_dleq_verify_with_wrong_session() {
+        // Test that a DLEQ proof does not verify with the wrong session ID.
+        let sid = "test_session";
+        let wrong_sid = "wrong_session";
+        let pid = 1;
+        let x = Scalar::random(&mut OsRng);
+        let base1 = ProjectivePoint::GENERATOR;
+        let base2 = ProjectivePoint::GENERATOR * Scalar::from(7u64);
+        let y1 = base1 * x;
+        let y2 = base2 * x;
+
+        let proof = DLEqProof::prove(sid, pid, &x, &base1, &y1, &base2, &y2)
+            .expect("Proof generation failed");
+        let result = proof
+            .verify(wrong_sid, pid, &base1, &y1, &base2, &y2)
+            .expect("Verification failed");
+        assert!(!result, "DLEQ proof should not verify with wrong session ID");
+    }
+}
+
 #[test]
 fn test_proof_tamper_resistance() {
     // Test that the DLog proof is resistant to tampering.